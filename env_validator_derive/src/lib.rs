@@ -0,0 +1,189 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Type};
+
+/// Derives `Settings::from_env() -> Result<Settings, env_validator::ConfigError>`
+/// for a plain struct, mapping each field to an environment variable.
+///
+/// Field names are uppercased and joined with `_` to produce the variable
+/// name (e.g. `database_url` -> `DATABASE_URL`), unless overridden with
+/// `#[env(name = "...")]`. `Option<T>` fields and fields carrying
+/// `#[env(default = ...)]` are optional; every other field is required.
+/// All missing/invalid fields are aggregated into a single `ConfigError`
+/// rather than failing on the first one.
+#[proc_macro_derive(EnvConfig, attributes(env))]
+pub fn derive_env_config(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let struct_name = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input,
+                    "EnvConfig can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input,
+                "EnvConfig can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut field_blocks = Vec::new();
+    let mut field_names = Vec::new();
+    let mut is_option_flags = Vec::new();
+
+    for field in fields {
+        let field_name = field.ident.as_ref().expect("named field");
+        let field_ty = &field.ty;
+        let (env_name, default_lit) = parse_env_attr(field)?;
+        let is_option = is_option_type(field_ty);
+        let required = !is_option && default_lit.is_none();
+
+        field_names.push(field_name.clone());
+        is_option_flags.push(is_option);
+
+        let inner_ty = if is_option {
+            option_inner_type(field_ty)
+        } else {
+            field_ty.clone()
+        };
+
+        // A field is reported as missing only when it is absent or empty; a
+        // value that was found but is not valid UTF-8 or failed to parse is
+        // reported as invalid instead, via the arms below. These are mutually exclusive
+        // branches of this match, so a field can never land in both lists.
+        let missing_push = if required {
+            quote! { missing_vars.push(#env_name.to_string()); }
+        } else {
+            quote! {}
+        };
+
+        // A bad `#[env(default = ...)]` literal is reported the same way as
+        // a bad value found in the environment: pushed into `invalid_vars`,
+        // never an `unwrap()` panic.
+        let default = match &default_lit {
+            Some(lit) => quote! {
+                match #lit.to_string().parse::<#inner_ty>() {
+                    ::std::result::Result::Ok(v) => ::std::option::Option::Some(v),
+                    ::std::result::Result::Err(e) => {
+                        invalid_vars.push((#env_name.to_string(), format!("invalid default value: {}", e)));
+                        ::std::option::Option::None
+                    }
+                }
+            },
+            None => quote! { ::std::option::Option::None },
+        };
+
+        let fetch = quote! {
+            let #field_name: ::std::option::Option<#inner_ty> = match ::std::env::var(#env_name) {
+                ::std::result::Result::Ok(raw) if !raw.trim().is_empty() => {
+                    match raw.trim().parse::<#inner_ty>() {
+                        ::std::result::Result::Ok(v) => ::std::option::Option::Some(v),
+                        ::std::result::Result::Err(e) => {
+                            invalid_vars.push((#env_name.to_string(), e.to_string()));
+                            ::std::option::Option::None
+                        }
+                    }
+                }
+                ::std::result::Result::Err(::std::env::VarError::NotUnicode(_)) => {
+                    invalid_vars.push((#env_name.to_string(), "value is not valid UTF-8".to_string()));
+                    ::std::option::Option::None
+                }
+                _ => {
+                    #missing_push
+                    #default
+                }
+            };
+        };
+        field_blocks.push(fetch);
+    }
+
+    let struct_build = field_names.iter().zip(&is_option_flags).map(|(name, is_option)| {
+        if *is_option {
+            quote! { #name }
+        } else {
+            quote! { #name: #name.unwrap() }
+        }
+    });
+
+    Ok(quote! {
+        impl #struct_name {
+            pub fn from_env() -> ::std::result::Result<Self, ::env_validator::ConfigError> {
+                let mut missing_vars = ::std::vec::Vec::new();
+                let mut invalid_vars = ::std::vec::Vec::new();
+
+                #(#field_blocks)*
+
+                if !missing_vars.is_empty() || !invalid_vars.is_empty() {
+                    return ::std::result::Result::Err(::env_validator::ConfigError {
+                        missing_vars,
+                        invalid_vars,
+                    });
+                }
+
+                ::std::result::Result::Ok(Self {
+                    #(#struct_build),*
+                })
+            }
+        }
+    })
+}
+
+fn parse_env_attr(field: &syn::Field) -> syn::Result<(String, Option<syn::Lit>)> {
+    let default_name = field.ident.as_ref().unwrap().to_string().to_uppercase();
+    let mut name = default_name;
+    let mut default_lit = None;
+
+    for attr in &field.attrs {
+        if !attr.path().is_ident("env") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                name = value.value();
+            } else if meta.path.is_ident("default") {
+                let value: syn::Lit = meta.value()?.parse()?;
+                default_lit = Some(value);
+            } else {
+                return Err(meta.error("unsupported `env` attribute key, expected `name` or `default`"));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok((name, default_lit))
+}
+
+fn is_option_type(ty: &Type) -> bool {
+    if let Type::Path(p) = ty {
+        p.path.segments.last().map(|s| s.ident == "Option").unwrap_or(false)
+    } else {
+        false
+    }
+}
+
+fn option_inner_type(ty: &Type) -> Type {
+    if let Type::Path(p) = ty {
+        if let Some(seg) = p.path.segments.last() {
+            if let syn::PathArguments::AngleBracketed(args) = &seg.arguments {
+                if let Some(syn::GenericArgument::Type(inner)) = args.args.first() {
+                    return inner.clone();
+                }
+            }
+        }
+    }
+    ty.clone()
+}
@@ -3,6 +3,133 @@ use std::env;
 use std::fmt;
 use std::collections::HashMap;
 
+/// Derives `from_env()` for a struct, mapping each field to an environment
+/// variable. See the `env_validator_derive` crate docs for the attributes
+/// this supports (`#[env(name = "...")]`, `#[env(default = ...)]`).
+#[cfg(feature = "derive")]
+pub use env_validator_derive::EnvConfig;
+
+type Check = Box<dyn Fn(&str) -> Result<(), String>>;
+
+/// Describes how a single environment variable should be validated.
+///
+/// A `VarSpec` names a variable, marks it required or optional, and attaches
+/// a typed parser plus any number of constraints. Build one with `VarSpec::new::<T>`
+/// and chain constraints with `min`/`max`/`one_of`/`regex`/`custom`.
+pub struct VarSpec {
+    pub name: &'static str,
+    pub required: bool,
+    default: Option<String>,
+    checks: Vec<Check>,
+}
+
+impl VarSpec {
+    /// A required variable that must parse as `T`, with no additional constraints.
+    pub fn new<T>(name: &'static str) -> Self
+    where
+        T: std::str::FromStr,
+        T::Err: fmt::Display,
+    {
+        VarSpec {
+            name,
+            required: true,
+            default: None,
+            checks: vec![Box::new(|raw: &str| {
+                raw.parse::<T>().map(|_| ()).map_err(|e| e.to_string())
+            })],
+        }
+    }
+
+    /// Marks this variable as optional; it may be absent without failing validation.
+    pub fn optional(mut self) -> Self {
+        self.required = false;
+        self
+    }
+
+    /// Marks this variable as optional and supplies a fallback value used to
+    /// populate `EnvConfig` when the variable is absent from the environment.
+    pub fn default(mut self, default: impl Into<String>) -> Self {
+        self.required = false;
+        self.default = Some(default.into());
+        self
+    }
+
+    /// Requires the parsed value to be `>= min`.
+    pub fn min<T>(mut self, min: T) -> Self
+    where
+        T: std::str::FromStr + PartialOrd + fmt::Display + 'static,
+        T::Err: fmt::Display,
+    {
+        self.checks.push(Box::new(move |raw: &str| {
+            let val = raw.parse::<T>().map_err(|e| e.to_string())?;
+            if val < min {
+                Err(format!("{} is below the minimum of {}", val, min))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires the parsed value to be `<= max`.
+    pub fn max<T>(mut self, max: T) -> Self
+    where
+        T: std::str::FromStr + PartialOrd + fmt::Display + 'static,
+        T::Err: fmt::Display,
+    {
+        self.checks.push(Box::new(move |raw: &str| {
+            let val = raw.parse::<T>().map_err(|e| e.to_string())?;
+            if val > max {
+                Err(format!("{} is above the maximum of {}", val, max))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires the raw value to be one of `allowed`.
+    pub fn one_of(mut self, allowed: &[&str]) -> Self {
+        let allowed: Vec<String> = allowed.iter().map(|s| s.to_string()).collect();
+        self.checks.push(Box::new(move |raw: &str| {
+            if allowed.iter().any(|a| a == raw) {
+                Ok(())
+            } else {
+                Err(format!("value must be one of {:?}", allowed))
+            }
+        }));
+        self
+    }
+
+    /// Requires the raw value to match a regular expression.
+    pub fn regex(mut self, pattern: &str) -> Self {
+        let pattern = pattern.to_string();
+        self.checks.push(Box::new(move |raw: &str| {
+            let re = regex::Regex::new(&pattern).map_err(|e| e.to_string())?;
+            if re.is_match(raw) {
+                Ok(())
+            } else {
+                Err(format!("value does not match pattern '{}'", pattern))
+            }
+        }));
+        self
+    }
+
+    /// Requires `f` to succeed on the raw value; `f` returns the failure reason.
+    pub fn custom(mut self, f: fn(&str) -> Result<(), String>) -> Self {
+        self.checks.push(Box::new(f));
+        self
+    }
+
+    /// Runs every parser/constraint against `raw`, stopping at the first failure.
+    fn check(&self, raw: &str) -> Result<(), String> {
+        for check in &self.checks {
+            check(raw)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub struct ConfigError {
     pub missing_vars: Vec<String>,
@@ -35,47 +162,346 @@ impl std::error::Error for ConfigError {}
 
 #[derive(Clone, Debug)]
 pub struct EnvConfig {
-    vars: HashMap<String, String>,
+    vars: HashMap<String, std::ffi::OsString>,
+    /// Namespace prefix set by `validate_with_prefix`, used to normalize keys
+    /// passed to `get`/`get_parsed`/etc. into the `PREFIX_SCREAMING_SNAKE`
+    /// form they were stored under.
+    prefix: Option<String>,
 }
 
 impl EnvConfig {
-    pub fn get(&self, key: &str) -> Option<&String> {
-        self.vars.get(key)
+    /// Resolves a lookup key to the form it was stored under: as-is when
+    /// there's no prefix, or normalized (uppercased, dashes to underscores,
+    /// namespaced) when this config was built via `validate_with_prefix`.
+    fn resolve(&self, key: &str) -> Option<&std::ffi::OsString> {
+        match &self.prefix {
+            Some(prefix) => self.vars.get(&normalize_key(prefix, key)),
+            None => self.vars.get(key),
+        }
     }
-    
+
+    /// Resolves `key` and requires the value to be valid UTF-8, for the
+    /// `String`/parsed accessors below.
+    fn resolve_str(&self, key: &str) -> Result<&str, String> {
+        self.resolve(key)
+            .ok_or_else(|| format!("Key '{}' not found", key))?
+            .to_str()
+            .ok_or_else(|| format!("Key '{}' is not valid UTF-8", key))
+    }
+
+    /// Returns the raw value for `key`, if present and valid UTF-8.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.resolve_str(key).ok()
+    }
+
+    /// Returns the raw, possibly non-UTF-8 value for `key`, e.g. a filesystem
+    /// path captured without lossy conversion.
+    pub fn get_os(&self, key: &str) -> Option<&std::ffi::OsString> {
+        self.resolve(key)
+    }
+
     pub fn get_parsed<T>(&self, key: &str) -> Result<T, Box<dyn std::error::Error>>
     where
         T: std::str::FromStr,
         T::Err: std::error::Error + 'static,
     {
-        self.vars.get(key)
-            .ok_or_else(|| format!("Key '{}' not found", key).into())
-            .and_then(|v| v.parse::<T>().map_err(|e| e.into()))
+        self.resolve_str(key)?.parse::<T>().map_err(|e| e.into())
+    }
+
+    /// Returns the raw value for `key`, or `default` if it isn't set or isn't valid UTF-8.
+    pub fn get_or<'a>(&'a self, key: &str, default: &'a str) -> &'a str {
+        self.resolve_str(key).unwrap_or(default)
+    }
+
+    /// Parses the value for `key` as `T`, or returns `default` if it isn't set.
+    pub fn get_parsed_or<T>(&self, key: &str, default: T) -> Result<T, Box<dyn std::error::Error>>
+    where
+        T: std::str::FromStr,
+        T::Err: std::error::Error + 'static,
+    {
+        match self.resolve(key) {
+            Some(_) => self.resolve_str(key)?.parse::<T>().map_err(|e| e.into()),
+            None => Ok(default),
+        }
+    }
+
+    /// Splits the value for `key` on `sep`, trims each element, and parses it as `T`.
+    ///
+    /// If any element fails to parse, the error names the offending element
+    /// and its index so the user can fix the exact entry.
+    pub fn get_vec<T>(&self, key: &str, sep: char) -> Result<Vec<T>, Box<dyn std::error::Error>>
+    where
+        T: std::str::FromStr,
+        T::Err: std::error::Error + 'static,
+    {
+        let raw = self.resolve_str(key)?;
+
+        raw.split(sep)
+            .enumerate()
+            .map(|(i, part)| {
+                part.trim().parse::<T>().map_err(|e| {
+                    format!("element {} ('{}') of '{}' failed to parse: {}", i, part.trim(), key, e).into()
+                })
+            })
+            .collect()
+    }
+
+    /// Splits the value for `key` into `entry_sep`-separated `key{kv_sep}value`
+    /// pairs, trims each side, and parses the value as `V`.
+    ///
+    /// If any entry fails to parse, the error names the offending entry and
+    /// its index so the user can fix the exact one.
+    pub fn get_map<V>(&self, key: &str, entry_sep: char, kv_sep: char) -> Result<HashMap<String, V>, Box<dyn std::error::Error>>
+    where
+        V: std::str::FromStr,
+        V::Err: std::error::Error + 'static,
+    {
+        let raw = self.resolve_str(key)?;
+
+        raw.split(entry_sep)
+            .enumerate()
+            .map(|(i, entry)| {
+                let entry = entry.trim();
+                let (k, v) = entry.split_once(kv_sep).ok_or_else(|| {
+                    format!("entry {} ('{}') of '{}' is not in 'key{}value' form", i, entry, key, kv_sep)
+                })?;
+                let value = v.trim().parse::<V>().map_err(|e| {
+                    format!("entry {} ('{}') of '{}' failed to parse: {}", i, entry, key, e)
+                })?;
+                Ok((k.trim().to_string(), value))
+            })
+            .collect()
+    }
+}
+
+/// Fetches `var_name` via `env::var_os`, retaining its raw `OsString` so
+/// non-UTF-8 values (e.g. `PATH` entries) aren't lossily converted or
+/// dropped. Emptiness is checked on a lossy view purely to decide presence.
+fn lookup_os(var_name: &str) -> Option<std::ffi::OsString> {
+    match env::var_os(var_name) {
+        Some(val) if !val.to_string_lossy().trim().is_empty() => Some(val),
+        _ => None,
     }
 }
 
 pub fn validate_env_vars(required_vars: &[&str]) -> Result<EnvConfig, ConfigError> {
     dotenv().ok();
-    
+
     let mut missing_vars = Vec::new();
     let mut vars = HashMap::new();
-    
+
     for &var_name in required_vars {
-        match env::var(var_name) {
-            Ok(val) if !val.trim().is_empty() => {
+        match lookup_os(var_name) {
+            Some(val) => {
                 vars.insert(var_name.to_string(), val);
             }
-            Ok(_) => {
-                missing_vars.push(format!("{} (empty)", var_name));
+            None => match env::var_os(var_name) {
+                Some(_) => missing_vars.push(format!("{} (empty)", var_name)),
+                None => missing_vars.push(var_name.to_string()),
+            },
+        }
+    }
+
+    if missing_vars.is_empty() {
+        Ok(EnvConfig { vars, prefix: None })
+    } else {
+        Err(ConfigError {
+            missing_vars,
+            invalid_vars: Vec::new(),
+        })
+    }
+}
+
+/// Validates environment variables against a schema of `VarSpec`s.
+///
+/// Every variable is checked, even after an earlier one fails: missing
+/// required variables are collected into `ConfigError.missing_vars`, and
+/// variables whose value fails its parser/constraints are collected into
+/// `ConfigError.invalid_vars` alongside a human-readable reason. This means
+/// a single call reports every problem in the environment at once, rather
+/// than stopping at the first one.
+pub fn validate_env_schema(schema: &[VarSpec]) -> Result<EnvConfig, ConfigError> {
+    dotenv().ok();
+
+    let mut missing_vars = Vec::new();
+    let mut invalid_vars = Vec::new();
+    let mut vars = HashMap::new();
+
+    for spec in schema {
+        match lookup_os(spec.name) {
+            Some(val) => match val.to_str() {
+                Some(s) => match spec.check(s) {
+                    Ok(()) => {
+                        vars.insert(spec.name.to_string(), val);
+                    }
+                    Err(reason) => {
+                        invalid_vars.push((spec.name.to_string(), reason));
+                    }
+                },
+                None => {
+                    invalid_vars.push((spec.name.to_string(), "value is not valid UTF-8".to_string()));
+                }
+            },
+            None => {
+                let present_but_empty = env::var_os(spec.name).is_some();
+                if spec.required {
+                    if present_but_empty {
+                        missing_vars.push(format!("{} (empty)", spec.name));
+                    } else {
+                        missing_vars.push(spec.name.to_string());
+                    }
+                } else if let Some(default) = &spec.default {
+                    match spec.check(default) {
+                        Ok(()) => {
+                            vars.insert(spec.name.to_string(), default.clone().into());
+                        }
+                        Err(reason) => {
+                            invalid_vars.push((spec.name.to_string(), reason));
+                        }
+                    }
+                }
             }
-            Err(_) => {
-                missing_vars.push(var_name.to_string());
+        }
+    }
+
+    if missing_vars.is_empty() && invalid_vars.is_empty() {
+        Ok(EnvConfig { vars, prefix: None })
+    } else {
+        Err(ConfigError {
+            missing_vars,
+            invalid_vars,
+        })
+    }
+}
+
+/// Maps a friendly, possibly kebab-case key onto the `PREFIX_SCREAMING_SNAKE`
+/// environment variable name it corresponds to, e.g. `("myapp", "database-url")`
+/// -> `"MYAPP_DATABASE_URL"`.
+fn normalize_key(prefix: &str, key: &str) -> String {
+    format!("{}_{}", prefix.to_uppercase(), key.to_uppercase().replace('-', "_"))
+}
+
+/// Validates a namespaced group of environment variables under `prefix`.
+///
+/// Each entry in `keys` (conventionally kebab-case, e.g. `"database-url"`)
+/// is normalized and looked up as `PREFIX_DATABASE_URL`. The returned
+/// `EnvConfig` remembers the prefix, so `config.get("database-url")` and
+/// friends continue to resolve through the same normalization. This makes
+/// it easy to run several isolated components in one process, each reading
+/// only its own `PREFIX_*` variables.
+pub fn validate_with_prefix(prefix: &str, keys: &[&str]) -> Result<EnvConfig, ConfigError> {
+    dotenv().ok();
+
+    let mut missing_vars = Vec::new();
+    let mut vars = HashMap::new();
+
+    for &key in keys {
+        let env_name = normalize_key(prefix, key);
+        match lookup_os(&env_name) {
+            Some(val) => {
+                vars.insert(env_name, val);
             }
+            None => match env::var_os(&env_name) {
+                Some(_) => missing_vars.push(format!("{} (empty)", env_name)),
+                None => missing_vars.push(env_name),
+            },
         }
     }
-    
+
+    if missing_vars.is_empty() {
+        Ok(EnvConfig {
+            vars,
+            prefix: Some(prefix.to_string()),
+        })
+    } else {
+        Err(ConfigError {
+            missing_vars,
+            invalid_vars: Vec::new(),
+        })
+    }
+}
+
+/// A source that can resolve an environment variable name to a value.
+///
+/// `validate_from` resolves each required variable through an ordered list
+/// of sources, first hit wins. This makes it possible to layer `.env.local`
+/// over `.env` over the process environment, to test deterministically by
+/// injecting a `MapSource` instead of mutating global process state, and to
+/// add future sources (e.g. a secrets file) without touching the core
+/// validation loop.
+pub trait Source {
+    fn lookup(&self, key: &str) -> Option<String>;
+}
+
+/// Reads from the process environment via `std::env::var`.
+pub struct ProcessEnvSource;
+
+impl Source for ProcessEnvSource {
+    fn lookup(&self, key: &str) -> Option<String> {
+        env::var(key).ok()
+    }
+}
+
+/// Reads from a specific dotenv-formatted file, without touching the process
+/// environment or any globally loaded `.env`.
+pub struct DotenvFileSource {
+    path: std::path::PathBuf,
+}
+
+impl DotenvFileSource {
+    pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+        DotenvFileSource { path: path.into() }
+    }
+}
+
+impl Source for DotenvFileSource {
+    fn lookup(&self, key: &str) -> Option<String> {
+        dotenvy::from_path_iter(&self.path)
+            .ok()?
+            .filter_map(Result::ok)
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v)
+    }
+}
+
+/// Reads from an in-memory map, useful for deterministic tests.
+pub struct MapSource {
+    map: HashMap<String, String>,
+}
+
+impl MapSource {
+    pub fn new(map: HashMap<String, String>) -> Self {
+        MapSource { map }
+    }
+}
+
+impl Source for MapSource {
+    fn lookup(&self, key: &str) -> Option<String> {
+        self.map.get(key).cloned()
+    }
+}
+
+/// Validates `required_vars` by resolving each one through `sources`, in
+/// order, taking the first non-empty value found.
+pub fn validate_from(sources: &[Box<dyn Source>], required_vars: &[&str]) -> Result<EnvConfig, ConfigError> {
+    let mut missing_vars = Vec::new();
+    let mut vars = HashMap::new();
+
+    for &var_name in required_vars {
+        let found = sources.iter().find_map(|source| {
+            source.lookup(var_name).filter(|v| !v.trim().is_empty())
+        });
+
+        match found {
+            Some(val) => {
+                vars.insert(var_name.to_string(), val.into());
+            }
+            None => missing_vars.push(var_name.to_string()),
+        }
+    }
+
     if missing_vars.is_empty() {
-        Ok(EnvConfig { vars })
+        Ok(EnvConfig { vars, prefix: None })
     } else {
         Err(ConfigError {
             missing_vars,
@@ -92,6 +518,291 @@ macro_rules! validate_env {
     }};
 }
 
+#[cfg(test)]
+mod schema_tests {
+    use super::*;
+
+    #[test]
+    fn reports_every_problem_in_one_pass() {
+        std::env::remove_var("EVRS_SCHEMA_MISSING");
+        std::env::set_var("EVRS_SCHEMA_PORT", "99999");
+        std::env::set_var("EVRS_SCHEMA_LEVEL", "trace");
+
+        let schema = [
+            VarSpec::new::<u16>("EVRS_SCHEMA_MISSING"),
+            VarSpec::new::<u32>("EVRS_SCHEMA_PORT").max(65535u32),
+            VarSpec::new::<String>("EVRS_SCHEMA_LEVEL").one_of(&["debug", "info", "warn"]),
+        ];
+
+        let err = validate_env_schema(&schema).unwrap_err();
+        assert_eq!(err.missing_vars, vec!["EVRS_SCHEMA_MISSING".to_string()]);
+        assert_eq!(err.invalid_vars.len(), 2);
+        assert!(err.invalid_vars.iter().any(|(k, _)| k == "EVRS_SCHEMA_PORT"));
+        assert!(err.invalid_vars.iter().any(|(k, _)| k == "EVRS_SCHEMA_LEVEL"));
+    }
+
+    #[test]
+    fn accepts_values_within_constraints() {
+        std::env::set_var("EVRS_SCHEMA_OK_PORT", "8080");
+
+        let schema = [VarSpec::new::<u32>("EVRS_SCHEMA_OK_PORT").min(1024u32).max(65535u32)];
+        let config = validate_env_schema(&schema).unwrap();
+        assert_eq!(config.get_parsed::<u32>("EVRS_SCHEMA_OK_PORT").unwrap(), 8080);
+    }
+
+    #[test]
+    fn optional_missing_var_is_not_reported() {
+        std::env::remove_var("EVRS_SCHEMA_OPTIONAL");
+
+        let schema = [VarSpec::new::<u16>("EVRS_SCHEMA_OPTIONAL").optional()];
+        let config = validate_env_schema(&schema).unwrap();
+        assert!(config.get("EVRS_SCHEMA_OPTIONAL").is_none());
+    }
+
+    #[test]
+    fn a_default_that_violates_its_own_constraints_is_rejected() {
+        std::env::remove_var("EVRS_SCHEMA_BAD_DEFAULT");
+
+        let schema = [VarSpec::new::<u16>("EVRS_SCHEMA_BAD_DEFAULT")
+            .default("not_a_number")
+            .max(65535u16)];
+
+        let err = validate_env_schema(&schema).unwrap_err();
+        assert!(err.missing_vars.is_empty());
+        assert!(err.invalid_vars.iter().any(|(k, _)| k == "EVRS_SCHEMA_BAD_DEFAULT"));
+    }
+
+    #[test]
+    fn a_default_within_constraints_is_accepted() {
+        std::env::remove_var("EVRS_SCHEMA_OK_DEFAULT");
+
+        let schema = [VarSpec::new::<u16>("EVRS_SCHEMA_OK_DEFAULT")
+            .default("8080")
+            .max(65535u16)];
+
+        let config = validate_env_schema(&schema).unwrap();
+        assert_eq!(config.get_parsed::<u16>("EVRS_SCHEMA_OK_DEFAULT").unwrap(), 8080);
+    }
+}
+
+#[cfg(test)]
+mod accessor_tests {
+    use super::*;
+
+    #[test]
+    fn get_or_returns_the_value_when_present() {
+        std::env::set_var("EVRS_ACCESSOR_HOST", "db.internal");
+
+        let config = validate_env_vars(&["EVRS_ACCESSOR_HOST"]).unwrap();
+        assert_eq!(config.get_or("EVRS_ACCESSOR_HOST", "localhost"), "db.internal");
+    }
+
+    #[test]
+    fn get_or_falls_back_when_absent() {
+        std::env::remove_var("EVRS_ACCESSOR_MISSING_HOST");
+
+        let config = validate_env_vars(&[]).unwrap();
+        assert_eq!(config.get_or("EVRS_ACCESSOR_MISSING_HOST", "localhost"), "localhost");
+    }
+
+    #[test]
+    fn get_parsed_or_returns_the_parsed_value_when_present() {
+        std::env::set_var("EVRS_ACCESSOR_PORT", "9090");
+
+        let config = validate_env_vars(&["EVRS_ACCESSOR_PORT"]).unwrap();
+        assert_eq!(config.get_parsed_or::<u16>("EVRS_ACCESSOR_PORT", 8080).unwrap(), 9090);
+    }
+
+    #[test]
+    fn get_parsed_or_falls_back_when_absent() {
+        std::env::remove_var("EVRS_ACCESSOR_MISSING_PORT");
+
+        let config = validate_env_vars(&[]).unwrap();
+        assert_eq!(config.get_parsed_or::<u16>("EVRS_ACCESSOR_MISSING_PORT", 8080).unwrap(), 8080);
+    }
+
+    #[test]
+    fn get_parsed_or_still_reports_a_present_but_unparseable_value() {
+        std::env::set_var("EVRS_ACCESSOR_BAD_PORT", "not_a_number");
+
+        let config = validate_env_vars(&["EVRS_ACCESSOR_BAD_PORT"]).unwrap();
+        assert!(config.get_parsed_or::<u16>("EVRS_ACCESSOR_BAD_PORT", 8080).is_err());
+    }
+}
+
+#[cfg(test)]
+mod prefix_tests {
+    use super::*;
+
+    #[test]
+    fn normalize_key_uppercases_and_joins_with_the_prefix() {
+        assert_eq!(normalize_key("myapp", "database-url"), "MYAPP_DATABASE_URL");
+    }
+
+    #[test]
+    fn normalize_key_replaces_every_dash() {
+        assert_eq!(normalize_key("myapp", "max-idle-connections"), "MYAPP_MAX_IDLE_CONNECTIONS");
+    }
+
+    #[test]
+    fn validate_with_prefix_resolves_kebab_keys_under_the_namespace() {
+        std::env::set_var("EVRSPREFIX_DATABASE_URL", "postgres://localhost/app");
+
+        let config = validate_with_prefix("evrsprefix", &["database-url"]).unwrap();
+        assert_eq!(config.get("database-url"), Some("postgres://localhost/app"));
+    }
+
+    #[test]
+    fn validate_with_prefix_reports_missing_namespaced_vars() {
+        std::env::remove_var("EVRSPREFIX_MISSING_KEY");
+
+        let err = validate_with_prefix("evrsprefix", &["missing-key"]).unwrap_err();
+        assert_eq!(err.missing_vars, vec!["EVRSPREFIX_MISSING_KEY".to_string()]);
+    }
+}
+
+#[cfg(test)]
+mod os_tests {
+    use super::*;
+
+    #[test]
+    fn get_os_returns_the_raw_value() {
+        std::env::set_var("EVRS_OS_HOST", "db.internal");
+
+        let config = validate_env_vars(&["EVRS_OS_HOST"]).unwrap();
+        assert_eq!(
+            config.get_os("EVRS_OS_HOST"),
+            Some(&std::ffi::OsString::from("db.internal"))
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn get_os_retains_non_utf8_bytes_that_get_rejects() {
+        use std::os::unix::ffi::OsStringExt;
+
+        // 0x66 0x6f 0x80 0x6f is "fo<invalid>o", not valid UTF-8.
+        let raw = std::ffi::OsString::from_vec(vec![0x66, 0x6f, 0x80, 0x6f]);
+        std::env::set_var("EVRS_OS_NON_UTF8", &raw);
+
+        let config = validate_env_vars(&["EVRS_OS_NON_UTF8"]).unwrap();
+        assert_eq!(config.get_os("EVRS_OS_NON_UTF8"), Some(&raw));
+        assert!(config.get("EVRS_OS_NON_UTF8").is_none());
+    }
+}
+
+#[cfg(test)]
+mod source_tests {
+    use super::*;
+
+    #[test]
+    fn map_source_looks_up_its_entries() {
+        let mut map = HashMap::new();
+        map.insert("HOST".to_string(), "db.internal".to_string());
+        let source = MapSource::new(map);
+
+        assert_eq!(source.lookup("HOST"), Some("db.internal".to_string()));
+        assert_eq!(source.lookup("MISSING"), None);
+    }
+
+    #[test]
+    fn process_env_source_reads_the_real_environment() {
+        std::env::set_var("EVRS_SOURCE_PROCESS_HOST", "db.internal");
+
+        let source = ProcessEnvSource;
+        assert_eq!(source.lookup("EVRS_SOURCE_PROCESS_HOST"), Some("db.internal".to_string()));
+    }
+
+    #[test]
+    fn dotenv_file_source_reads_from_the_given_path() {
+        let path = std::env::temp_dir().join(format!("evrs_dotenv_source_test_{}.env", std::process::id()));
+        std::fs::write(&path, "HOST=db.internal\n").unwrap();
+
+        let source = DotenvFileSource::new(&path);
+        assert_eq!(source.lookup("HOST"), Some("db.internal".to_string()));
+        assert_eq!(source.lookup("MISSING"), None);
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn validate_from_takes_the_first_source_with_a_value() {
+        std::env::remove_var("EVRS_SOURCE_PRECEDENCE_HOST");
+
+        let mut overrides = HashMap::new();
+        overrides.insert("EVRS_SOURCE_PRECEDENCE_HOST".to_string(), "override.internal".to_string());
+        let mut fallback = HashMap::new();
+        fallback.insert("EVRS_SOURCE_PRECEDENCE_HOST".to_string(), "fallback.internal".to_string());
+
+        let sources: Vec<Box<dyn Source>> = vec![
+            Box::new(MapSource::new(overrides)),
+            Box::new(MapSource::new(fallback)),
+        ];
+
+        let config = validate_from(&sources, &["EVRS_SOURCE_PRECEDENCE_HOST"]).unwrap();
+        assert_eq!(config.get("EVRS_SOURCE_PRECEDENCE_HOST"), Some("override.internal"));
+    }
+
+    #[test]
+    fn validate_from_falls_through_an_empty_source_to_the_next_one() {
+        let mut empty = HashMap::new();
+        empty.insert("EVRS_SOURCE_FALLTHROUGH_HOST".to_string(), "".to_string());
+        let mut fallback = HashMap::new();
+        fallback.insert("EVRS_SOURCE_FALLTHROUGH_HOST".to_string(), "fallback.internal".to_string());
+
+        let sources: Vec<Box<dyn Source>> = vec![
+            Box::new(MapSource::new(empty)),
+            Box::new(MapSource::new(fallback)),
+        ];
+
+        let config = validate_from(&sources, &["EVRS_SOURCE_FALLTHROUGH_HOST"]).unwrap();
+        assert_eq!(config.get("EVRS_SOURCE_FALLTHROUGH_HOST"), Some("fallback.internal"));
+    }
+
+    #[test]
+    fn validate_from_reports_missing_when_no_source_has_it() {
+        let sources: Vec<Box<dyn Source>> = vec![Box::new(MapSource::new(HashMap::new()))];
+
+        let err = validate_from(&sources, &["EVRS_SOURCE_NOWHERE"]).unwrap_err();
+        assert_eq!(err.missing_vars, vec!["EVRS_SOURCE_NOWHERE".to_string()]);
+    }
+}
+
+#[cfg(test)]
+mod collection_tests {
+    use super::*;
+
+    #[test]
+    fn get_vec_parses_trimmed_elements() {
+        std::env::set_var("EVRS_VEC_HOSTS", "a.com, b.com ,c.com");
+
+        let config = validate_env_vars(&["EVRS_VEC_HOSTS"]).unwrap();
+        let hosts: Vec<String> = config.get_vec("EVRS_VEC_HOSTS", ',').unwrap();
+        assert_eq!(hosts, vec!["a.com", "b.com", "c.com"]);
+    }
+
+    #[test]
+    fn get_vec_names_the_offending_element() {
+        std::env::set_var("EVRS_VEC_PORTS", "80,not_a_number,443");
+
+        let config = validate_env_vars(&["EVRS_VEC_PORTS"]).unwrap();
+        let err = config.get_vec::<u16>("EVRS_VEC_PORTS", ',').unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("element 1"));
+        assert!(message.contains("not_a_number"));
+    }
+
+    #[test]
+    fn get_map_parses_entries() {
+        std::env::set_var("EVRS_MAP_FLAGS", "x=1;y=2");
+
+        let config = validate_env_vars(&["EVRS_MAP_FLAGS"]).unwrap();
+        let flags: HashMap<String, u32> = config.get_map("EVRS_MAP_FLAGS", ';', '=').unwrap();
+        assert_eq!(flags.get("x"), Some(&1));
+        assert_eq!(flags.get("y"), Some(&2));
+    }
+}
+
 /* Example usage:
 mod env_validator;
 
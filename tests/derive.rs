@@ -0,0 +1,97 @@
+use env_validator::EnvConfig;
+
+#[derive(EnvConfig)]
+struct FullSettings {
+    #[env(name = "EVRS_DERIVE_FULL_DATABASE_URL")]
+    database_url: String,
+    #[env(name = "EVRS_DERIVE_FULL_PORT", default = 8080)]
+    port: u16,
+    #[env(name = "EVRS_DERIVE_FULL_API_KEY")]
+    api_key: String,
+    #[env(name = "EVRS_DERIVE_FULL_REGION")]
+    region: Option<String>,
+}
+
+#[test]
+fn from_env_populates_every_field() {
+    std::env::set_var("EVRS_DERIVE_FULL_DATABASE_URL", "postgres://localhost/app");
+    std::env::set_var("EVRS_DERIVE_FULL_API_KEY", "secret");
+    std::env::set_var("EVRS_DERIVE_FULL_REGION", "us-east-1");
+    std::env::remove_var("EVRS_DERIVE_FULL_PORT");
+
+    let settings = FullSettings::from_env().unwrap();
+    assert_eq!(settings.database_url, "postgres://localhost/app");
+    assert_eq!(settings.port, 8080);
+    assert_eq!(settings.api_key, "secret");
+    assert_eq!(settings.region.as_deref(), Some("us-east-1"));
+}
+
+#[derive(EnvConfig)]
+struct DefaultedSettings {
+    #[env(name = "EVRS_DERIVE_DEFAULTED_PORT", default = 8080)]
+    port: u16,
+    #[env(name = "EVRS_DERIVE_DEFAULTED_REGION")]
+    region: Option<String>,
+}
+
+#[test]
+fn from_env_uses_default_and_allows_missing_option() {
+    std::env::remove_var("EVRS_DERIVE_DEFAULTED_PORT");
+    std::env::remove_var("EVRS_DERIVE_DEFAULTED_REGION");
+
+    let settings = DefaultedSettings::from_env().unwrap();
+    assert_eq!(settings.port, 8080);
+    assert_eq!(settings.region, None);
+}
+
+#[derive(EnvConfig, Debug)]
+#[allow(dead_code)]
+struct RequiredSettings {
+    #[env(name = "EVRS_DERIVE_REQUIRED_DATABASE_URL")]
+    database_url: String,
+    #[env(name = "EVRS_DERIVE_REQUIRED_API_KEY")]
+    api_key: String,
+}
+
+#[test]
+fn from_env_aggregates_missing_fields() {
+    std::env::remove_var("EVRS_DERIVE_REQUIRED_DATABASE_URL");
+    std::env::remove_var("EVRS_DERIVE_REQUIRED_API_KEY");
+
+    let err = RequiredSettings::from_env().unwrap_err();
+    assert!(err.missing_vars.contains(&"EVRS_DERIVE_REQUIRED_DATABASE_URL".to_string()));
+    assert!(err.missing_vars.contains(&"EVRS_DERIVE_REQUIRED_API_KEY".to_string()));
+    assert!(err.invalid_vars.is_empty());
+}
+
+#[derive(EnvConfig, Debug)]
+#[allow(dead_code)]
+struct TypedSettings {
+    #[env(name = "EVRS_DERIVE_TYPED_PORT")]
+    port: u16,
+}
+
+#[test]
+fn a_present_but_invalid_field_is_only_reported_as_invalid() {
+    std::env::set_var("EVRS_DERIVE_TYPED_PORT", "not_a_number");
+
+    let err = TypedSettings::from_env().unwrap_err();
+    assert!(!err.missing_vars.contains(&"EVRS_DERIVE_TYPED_PORT".to_string()));
+    assert!(err.invalid_vars.iter().any(|(k, _)| k == "EVRS_DERIVE_TYPED_PORT"));
+}
+
+#[derive(EnvConfig, Debug)]
+#[allow(dead_code)]
+struct BadDefaultSettings {
+    #[env(name = "EVRS_DERIVE_BAD_DEFAULT_PORT", default = "not_a_number")]
+    port: u16,
+}
+
+#[test]
+fn an_unparseable_default_literal_is_reported_as_invalid_not_a_panic() {
+    std::env::remove_var("EVRS_DERIVE_BAD_DEFAULT_PORT");
+
+    let err = BadDefaultSettings::from_env().unwrap_err();
+    assert!(err.missing_vars.is_empty());
+    assert!(err.invalid_vars.iter().any(|(k, _)| k == "EVRS_DERIVE_BAD_DEFAULT_PORT"));
+}